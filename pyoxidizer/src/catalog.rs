@@ -0,0 +1,251 @@
+//! Structured catalog of clipfix lints.
+//!
+//! This is the single source of truth `explain`, `list-lints`, and the
+//! SARIF `rules` array all read from, so the three stay consistent
+//! instead of drifting into their own copies of rule metadata.
+
+/// A lint's category, matching the groupings clippy itself uses.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Category {
+    Correctness,
+    Style,
+    Complexity,
+    Perf,
+    Pedantic,
+    Nursery,
+}
+
+impl Category {
+    pub const ALL: [Category; 6] = [
+        Category::Correctness,
+        Category::Style,
+        Category::Complexity,
+        Category::Perf,
+        Category::Pedantic,
+        Category::Nursery,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Category::Correctness => "correctness",
+            Category::Style => "style",
+            Category::Complexity => "complexity",
+            Category::Perf => "perf",
+            Category::Pedantic => "pedantic",
+            Category::Nursery => "nursery",
+        }
+    }
+}
+
+/// Default severity for a lint that hasn't been explicitly configured.
+#[derive(Clone, Copy)]
+pub enum Level {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Level {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warning => "warning",
+            Level::Note => "note",
+        }
+    }
+}
+
+/// A single clipfix lint's static metadata.
+pub struct Rule {
+    pub id: &'static str,
+    pub category: Category,
+    pub level: Level,
+    pub description: &'static str,
+    pub rationale: &'static str,
+    pub good_example: &'static str,
+    pub bad_example: &'static str,
+}
+
+/// All known clipfix rules, in catalog order.
+///
+/// This list is hand-maintained for now; it mirrors whatever `clipfix`
+/// itself registers and is the single source `explain`, `list-lints`,
+/// and the SARIF renderer all consult.
+pub const RULES: &[Rule] = &[
+    Rule {
+        id: "unused-import",
+        category: Category::Style,
+        level: Level::Warning,
+        description: "An imported name is never referenced in the module.",
+        rationale: "Unused imports add noise, slow down tooling, and often \
+            signal dead code left over from a refactor.",
+        good_example: "import json\n\njson.dumps(payload)",
+        bad_example: "import json\nimport os\n\njson.dumps(payload)",
+    },
+    Rule {
+        id: "bare-except",
+        category: Category::Correctness,
+        level: Level::Error,
+        description: "A `except:` clause with no exception type catches \
+            everything, including `KeyboardInterrupt` and `SystemExit`.",
+        rationale: "Swallowing every exception hides bugs and makes \
+            programs hard to interrupt or debug.",
+        good_example: "try:\n    risky()\nexcept ValueError:\n    handle()",
+        bad_example: "try:\n    risky()\nexcept:\n    pass",
+    },
+    Rule {
+        id: "mutable-default-arg",
+        category: Category::Correctness,
+        level: Level::Warning,
+        description: "A mutable value (list, dict, set) is used as a \
+            default argument.",
+        rationale: "Default arguments are evaluated once at function \
+            definition time, so a mutable default is shared and mutated \
+            across every call that doesn't override it.",
+        good_example: "def add(item, items=None):\n    items = items or []\n    items.append(item)",
+        bad_example: "def add(item, items=[]):\n    items.append(item)",
+    },
+];
+
+/// Render the catalog as a plain-text table, one row per rule.
+pub fn render_table() -> String {
+    let mut out = String::new();
+    for category in Category::ALL {
+        let rules: Vec<&Rule> = RULES.iter().filter(|rule| rule.category == category).collect();
+        if rules.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("{}\n", category.name()));
+        for rule in rules {
+            out.push_str(&format!(
+                "  {:<24} {:<8} {}\n",
+                rule.id,
+                rule.level.name(),
+                rule.description
+            ));
+        }
+    }
+    out
+}
+
+/// Render the catalog as a Markdown table grouped by category, with a
+/// stable per-lint anchor so individual rules can be linked to directly.
+pub fn render_markdown() -> String {
+    let mut out = String::new();
+    for category in Category::ALL {
+        let rules: Vec<&Rule> = RULES.iter().filter(|rule| rule.category == category).collect();
+        if rules.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("## {}\n\n", category.name()));
+        out.push_str("| Lint | Default level | Description |\n");
+        out.push_str("| --- | --- | --- |\n");
+        for rule in &rules {
+            out.push_str(&format!(
+                "| [`{id}`](#{id}) | {level} | {description} |\n",
+                id = rule.id,
+                level = rule.level.name(),
+                description = rule.description
+            ));
+        }
+        out.push('\n');
+
+        // One heading per lint so the `#{id}` links above actually land
+        // somewhere; an explicit `<a id>` makes the anchor stable even if
+        // a renderer's heading-to-slug rule ever changes.
+        for rule in &rules {
+            out.push_str(&format!(
+                "<a id=\"{id}\"></a>\n### {id}\n\n{description}\n\n",
+                id = rule.id,
+                description = rule.description
+            ));
+        }
+    }
+    out
+}
+
+/// Look up a rule by id.
+pub fn find(id: &str) -> Option<&'static Rule> {
+    RULES.iter().find(|rule| rule.id == id)
+}
+
+/// Suggest the closest known rule id to an unrecognized one, for the
+/// "did you mean" hint on a miss.
+pub fn suggest(id: &str) -> Option<&'static str> {
+    RULES
+        .iter()
+        .map(|rule| (rule.id, edit_distance(id, rule.id)))
+        .min_by_key(|(_, distance)| *distance)
+        .filter(|(_, distance)| *distance <= 3)
+        .map(|(rule_id, _)| rule_id)
+}
+
+/// Classic Levenshtein edit distance, used only for the small "did you
+/// mean" suggestion above — not performance sensitive.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, &ca) in a.iter().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == cb {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(row[j + 1])
+            };
+            prev = cur;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance_same_string_is_zero() {
+        assert_eq!(edit_distance("bare-except", "bare-except"), 0);
+    }
+
+    #[test]
+    fn edit_distance_counts_single_substitution() {
+        assert_eq!(edit_distance("bare-except", "bare-excspt"), 1);
+    }
+
+    #[test]
+    fn edit_distance_counts_insertion_and_deletion() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("abc", ""), 3);
+    }
+
+    #[test]
+    fn suggest_finds_close_typo() {
+        assert_eq!(suggest("bare-excpt"), Some("bare-except"));
+    }
+
+    #[test]
+    fn suggest_returns_none_when_nothing_is_close() {
+        assert_eq!(suggest("totally-unrelated-lint-name"), None);
+    }
+
+    #[test]
+    fn find_looks_up_known_and_unknown_ids() {
+        assert!(find("unused-import").is_some());
+        assert!(find("not-a-real-lint").is_none());
+    }
+
+    #[test]
+    fn render_markdown_links_land_on_a_matching_anchor() {
+        let markdown = render_markdown();
+        for rule in RULES {
+            assert!(markdown.contains(&format!("(#{})", rule.id)));
+            assert!(markdown.contains(&format!("<a id=\"{}\"></a>", rule.id)));
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Deterministic application of clipfix's suggested edits.
+//!
+//! `clipfix` decides *what* to change; this module decides *how* to land
+//! those changes safely — sorting edits within a file, skipping anything
+//! that overlaps, and either writing the result or printing a preview
+//! diff, mirroring `cargo clippy --fix`'s safety model.
+
+use std::fs;
+
+use similar::TextDiff;
+
+use crate::catalog;
+
+/// A single suggested edit, as reported by `clipfix`: replace the bytes
+/// `[start, end)` of `path` with `replacement`.
+pub struct Edit {
+    pub path: String,
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+    pub rule_id: String,
+}
+
+/// Outcome of applying (or previewing) a batch of edits.
+pub struct Report {
+    pub applied: usize,
+    pub skipped: usize,
+}
+
+/// Keep only edits whose rule belongs to `category`. `None` means no
+/// `--fix-by-category` filter was given, so every edit passes through.
+pub fn filter_by_category(edits: Vec<Edit>, category: Option<&str>) -> Vec<Edit> {
+    let Some(category) = category else {
+        return edits;
+    };
+
+    edits
+        .into_iter()
+        .filter(|edit| {
+            catalog::find(&edit.rule_id)
+                .map(|rule| rule.category.name() == category)
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+/// Apply `edits` to every file they touch, either writing the result to
+/// disk or printing a unified diff, depending on `dry_run`.
+pub fn run(edits: Vec<Edit>, dry_run: bool) -> std::io::Result<Report> {
+    let mut by_file: std::collections::BTreeMap<String, Vec<Edit>> = std::collections::BTreeMap::new();
+    for edit in edits {
+        by_file.entry(edit.path.clone()).or_default().push(edit);
+    }
+
+    let mut report = Report { applied: 0, skipped: 0 };
+
+    for (path, mut file_edits) in by_file {
+        file_edits.sort_by_key(|edit| edit.start);
+        let (kept, skipped) = drop_overlapping(file_edits);
+        report.skipped += skipped;
+
+        if kept.is_empty() {
+            continue;
+        }
+
+        let original = fs::read_to_string(&path)?;
+        let updated = apply_edits(&original, &kept);
+        report.applied += kept.len();
+
+        if dry_run {
+            print_diff(&path, &original, &updated);
+        } else {
+            fs::write(&path, updated)?;
+        }
+    }
+
+    Ok(report)
+}
+
+/// Walk edits in start order, dropping any whose range starts before the
+/// previous kept edit ends, so we never splice two conflicting edits
+/// into the same region of a file.
+fn drop_overlapping(edits: Vec<Edit>) -> (Vec<Edit>, usize) {
+    let mut kept: Vec<Edit> = Vec::new();
+    let mut skipped = 0;
+    let mut cursor = 0;
+
+    for edit in edits {
+        if edit.start < cursor {
+            skipped += 1;
+            continue;
+        }
+        cursor = edit.end;
+        kept.push(edit);
+    }
+
+    (kept, skipped)
+}
+
+/// Splice non-overlapping, start-sorted edits into `original`.
+fn apply_edits(original: &str, edits: &[Edit]) -> String {
+    let mut result = String::with_capacity(original.len());
+    let mut cursor = 0;
+
+    for edit in edits {
+        result.push_str(&original[cursor..edit.start]);
+        result.push_str(&edit.replacement);
+        cursor = edit.end;
+    }
+    result.push_str(&original[cursor..]);
+
+    result
+}
+
+fn print_diff(path: &str, original: &str, updated: &str) {
+    let diff = TextDiff::from_lines(original, updated);
+    print!(
+        "{}",
+        diff.unified_diff()
+            .header(&format!("a/{path}"), &format!("b/{path}"))
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(start: usize, end: usize, replacement: &str) -> Edit {
+        Edit {
+            path: "a.py".to_string(),
+            start,
+            end,
+            replacement: replacement.to_string(),
+            rule_id: "unused-import".to_string(),
+        }
+    }
+
+    #[test]
+    fn drop_overlapping_keeps_disjoint_edits_in_order() {
+        let edits = vec![edit(0, 2, "a"), edit(2, 4, "b"), edit(4, 6, "c")];
+        let (kept, skipped) = drop_overlapping(edits);
+        assert_eq!(skipped, 0);
+        assert_eq!(kept.len(), 3);
+    }
+
+    #[test]
+    fn drop_overlapping_skips_edits_that_start_inside_a_kept_edit() {
+        let edits = vec![edit(0, 5, "a"), edit(3, 8, "b"), edit(5, 10, "c")];
+        let (kept, skipped) = drop_overlapping(edits);
+        assert_eq!(skipped, 1);
+        assert_eq!(kept.len(), 2);
+        assert_eq!((kept[0].start, kept[0].end), (0, 5));
+        assert_eq!((kept[1].start, kept[1].end), (5, 10));
+    }
+
+    #[test]
+    fn drop_overlapping_keeps_back_to_back_edits_that_touch_but_dont_overlap() {
+        let edits = vec![edit(0, 3, "a"), edit(3, 6, "b")];
+        let (kept, skipped) = drop_overlapping(edits);
+        assert_eq!(skipped, 0);
+        assert_eq!(kept.len(), 2);
+    }
+
+    #[test]
+    fn apply_edits_splices_replacements_at_byte_offsets() {
+        let original = "import os\nimport json\n";
+        let edits = vec![edit(0, 10, "")];
+        assert_eq!(apply_edits(original, &edits), "import json\n");
+    }
+
+    #[test]
+    fn apply_edits_handles_multibyte_utf8_boundaries() {
+        let original = "x = \"café\"\n";
+        let start = original.find('"').unwrap();
+        let end = original.rfind('"').unwrap() + 1;
+        let edits = vec![edit(start, end, "\"tea\"")];
+        assert_eq!(apply_edits(original, &edits), "x = \"tea\"\n");
+    }
+
+    #[test]
+    fn filter_by_category_keeps_only_matching_rules() {
+        let edits = vec![
+            edit(0, 1, "x"), // unused-import -> style
+            Edit {
+                rule_id: "bare-except".to_string(),
+                ..edit(2, 3, "y")
+            },
+        ];
+        let filtered = filter_by_category(edits, Some("style"));
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].rule_id, "unused-import");
+    }
+
+    #[test]
+    fn filter_by_category_passes_everything_through_when_unset() {
+        let edits = vec![edit(0, 1, "x"), edit(2, 3, "y")];
+        assert_eq!(filter_by_category(edits, None).len(), 2);
+    }
+}
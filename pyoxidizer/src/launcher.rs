@@ -0,0 +1,266 @@
+//! Top-level subcommand dispatch for the `eclip` launcher.
+//!
+//! The embedded Python interpreter only ever sees `sys.argv` and a single
+//! entry point to call; this module is responsible for deciding which
+//! entry point that is before handing control to `clipfix`.
+
+use pyoxidizer::pyembed::{MainPythonInterpreter, Python, PyResult};
+
+/// The subcommands `eclip` understands at the Rust layer.
+///
+/// Each variant is dispatched to its own handler in [`run`]: `Check` calls
+/// the `clipfix.main` entry point (or collects findings for SARIF), `Fix`
+/// collects edits from `clipfix.fix`, `Explain` and `ListLints` read the
+/// Rust catalog without starting the interpreter, and `Lsp` runs
+/// [`crate::lsp::serve`]. Anything that doesn't match a known subcommand
+/// is treated as `Check` with the raw arguments passed straight through,
+/// mirroring `cargo clippy`'s default behavior when invoked with bare
+/// file paths.
+enum Subcommand {
+    Check,
+    Fix,
+    Explain,
+    ListLints,
+    Lsp,
+}
+
+impl Subcommand {
+    fn parse(name: &str) -> Subcommand {
+        match name {
+            "fix" => Subcommand::Fix,
+            "explain" => Subcommand::Explain,
+            "list-lints" => Subcommand::ListLints,
+            "lsp" => Subcommand::Lsp,
+            _ => Subcommand::Check,
+        }
+    }
+
+    /// The `clipfix` module path whose `main()` is called, with no
+    /// arguments, once `sys.argv` is set. Only `Check` with a non-SARIF
+    /// format reaches that call in [`run`]; the other variants return
+    /// from their own handlers first.
+    fn entry_point(&self) -> &'static str {
+        match self {
+            Subcommand::Check => "clipfix.main",
+            Subcommand::Fix => "clipfix.fix",
+            Subcommand::Explain => "clipfix.explain",
+            Subcommand::ListLints => "clipfix.catalog",
+            // `Lsp` doesn't call through a single `main()` entry point;
+            // the interpreter is handed to `crate::lsp::serve` instead so
+            // it can stay alive across the whole editor session.
+            Subcommand::Lsp => "",
+        }
+    }
+}
+
+/// Output format requested via `--format`. `Text` is clipfix's existing
+/// human-readable output and stays the default; for `Sarif`, clipfix only
+/// collects the findings and the document is rendered on the Rust side
+/// of the launcher via [`crate::sarif`].
+enum OutputFormat {
+    Text,
+    Sarif,
+    Markdown,
+}
+
+/// Pull a `--format <fmt>` pair out of the argument list, if present,
+/// leaving the rest of the arguments untouched for `clipfix` to parse.
+fn take_format(args: &mut Vec<String>) -> OutputFormat {
+    if let Some(index) = args.iter().position(|arg| arg == "--format") {
+        let value = args.get(index + 1).cloned().unwrap_or_default();
+        args.drain(index..=(index + 1).min(args.len() - 1));
+        match value.as_str() {
+            "sarif" => return OutputFormat::Sarif,
+            "markdown" => return OutputFormat::Markdown,
+            _ => {}
+        }
+    }
+    OutputFormat::Text
+}
+
+/// Pull `fix`'s own flags — `--dry-run` and `--fix-by-category <cat>` —
+/// out of the argument list, leaving the rest for `clipfix` to parse.
+fn take_fix_options(args: &mut Vec<String>) -> (bool, Option<String>) {
+    let dry_run = if let Some(index) = args.iter().position(|arg| arg == "--dry-run") {
+        args.remove(index);
+        true
+    } else {
+        false
+    };
+
+    let category = if let Some(index) = args.iter().position(|arg| arg == "--fix-by-category") {
+        let value = args.get(index + 1).cloned();
+        args.drain(index..=(index + 1).min(args.len() - 1));
+        value
+    } else {
+        None
+    };
+
+    (dry_run, category)
+}
+
+/// Collect `std::env::args()`, pick the subcommand, and run the matching
+/// `clipfix` entry point with a real `sys.argv`.
+///
+/// The first argument (argv[0]) is always the program name; if a second
+/// argument names a known subcommand it is consumed here and the
+/// remainder is forwarded as-is, so `clipfix`'s own argument parsers still
+/// see a conventional `argv`.
+pub fn run() -> i32 {
+    let raw_args: Vec<String> = std::env::args().collect();
+    let (subcommand, mut forwarded_args) = match raw_args.get(1).map(String::as_str) {
+        Some(name @ ("check" | "fix" | "explain" | "list-lints" | "lsp" | "--lsp")) => {
+            let mut rest = raw_args.clone();
+            rest.remove(1);
+            (Subcommand::parse(name.trim_start_matches("--")), rest)
+        }
+        _ => (Subcommand::Check, raw_args),
+    };
+    let format = take_format(&mut forwarded_args);
+    let (dry_run, fix_category) = take_fix_options(&mut forwarded_args);
+
+    if let Subcommand::Explain = subcommand {
+        // `explain` only reads the static Rust catalog, so there's no
+        // reason to pay for interpreter startup at all.
+        return run_explain(&forwarded_args);
+    }
+
+    if let Subcommand::ListLints = subcommand {
+        // Same reasoning as `explain`: the catalog is the Rust-side data
+        // source, so `list-lints` never needs the embedded interpreter.
+        return run_list_lints(&format);
+    }
+
+    let mut exit_code = 1;
+
+    MainPythonInterpreter::new().run(|py| {
+        let sys = py.import("sys")?;
+        sys.setattr("argv", forwarded_args.clone())?;
+
+        if let Subcommand::Lsp = subcommand {
+            exit_code = match crate::lsp::serve(py) {
+                Ok(()) => 0,
+                Err(_) => 1,
+            };
+            return Ok(());
+        }
+
+        if let (Subcommand::Check, OutputFormat::Sarif) = (&subcommand, &format) {
+            exit_code = run_sarif(py)?;
+            return Ok(());
+        }
+
+        if let Subcommand::Fix = subcommand {
+            exit_code = run_fix(py, dry_run, fix_category.as_deref())?;
+            return Ok(());
+        }
+
+        let module = py.import(subcommand.entry_point())?;
+        let result = module.call_method0("main")?;
+        exit_code = result.extract().unwrap_or(1);
+
+        Ok(())
+    });
+
+    exit_code
+}
+
+/// Print a rule's extended description, rationale, and examples, or a
+/// "did you mean" hint and a non-zero exit if the id isn't recognized.
+fn run_explain(args: &[String]) -> i32 {
+    let Some(id) = args.get(1) else {
+        eprintln!("usage: eclip explain <LINT>");
+        return 1;
+    };
+
+    match crate::catalog::find(id) {
+        Some(rule) => {
+            println!("{}\n", rule.id);
+            println!("{}\n", rule.description);
+            println!("{}\n", rule.rationale);
+            println!("Bad:\n{}\n", rule.bad_example);
+            println!("Good:\n{}", rule.good_example);
+            0
+        }
+        None => {
+            eprint!("unknown lint `{id}`");
+            match crate::catalog::suggest(id) {
+                Some(suggestion) => eprintln!(" — did you mean `{suggestion}`?"),
+                None => eprintln!(),
+            }
+            1
+        }
+    }
+}
+
+/// Print the catalog as a human table or a Markdown table grouped by
+/// category, depending on the requested `--format`.
+fn run_list_lints(format: &OutputFormat) -> i32 {
+    match format {
+        OutputFormat::Markdown => print!("{}", crate::catalog::render_markdown()),
+        _ => print!("{}", crate::catalog::render_table()),
+    }
+    0
+}
+
+/// Ask `clipfix` for its suggested edits, scope them to a category if
+/// requested, and either apply them or print a dry-run diff.
+fn run_fix(py: Python, dry_run: bool, category: Option<&str>) -> PyResult<i32> {
+    let fix_module = py.import("clipfix.fix")?;
+    let raw_edits: Vec<(String, usize, usize, String, String)> =
+        fix_module.call_method0("collect_edits")?.extract()?;
+
+    let edits = raw_edits
+        .into_iter()
+        .map(|(path, start, end, replacement, rule_id)| crate::fix::Edit {
+            path,
+            start,
+            end,
+            replacement,
+            rule_id,
+        })
+        .collect();
+    let edits = crate::fix::filter_by_category(edits, category);
+
+    let report = match crate::fix::run(edits, dry_run) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("error applying fixes: {err}");
+            return Ok(1);
+        }
+    };
+
+    if dry_run {
+        println!(
+            "{} fix(es) would be applied, {} skipped",
+            report.applied, report.skipped
+        );
+    } else {
+        println!("{} fix(es) applied, {} skipped", report.applied, report.skipped);
+    }
+
+    Ok(0)
+}
+
+/// Ask `clipfix` for its structured findings, pair them with the shared
+/// Rust rule catalog instead of letting clipfix print text, then render
+/// and print a SARIF document.
+fn run_sarif(py: Python) -> PyResult<i32> {
+    let main = py.import("clipfix.main")?;
+    let report = main.call_method0("collect_findings")?;
+
+    let version: String = main.getattr("__version__")?.extract().unwrap_or_default();
+    let findings_obj = report.get_item("findings")?;
+    let findings = crate::py_json::to_value(py, findings_obj)?
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+
+    let document = crate::sarif::render(&version, crate::catalog::RULES, &findings);
+    println!("{}", serde_json::to_string_pretty(&document).unwrap_or_default());
+
+    let has_errors = findings
+        .iter()
+        .any(|finding| finding["level"] == "error");
+    Ok(if has_errors { 1 } else { 0 })
+}
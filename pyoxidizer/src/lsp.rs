@@ -0,0 +1,246 @@
+//! A minimal LSP server that keeps one embedded interpreter alive for the
+//! lifetime of the editor session.
+//!
+//! Spinning up a `MainPythonInterpreter` is expensive (PyOxidizer has to
+//! unpack and initialize the whole embedded runtime), so unlike `check`,
+//! `fix`, `explain`, and `list-lints` — which each run once and exit —
+//! `lsp` mode starts the interpreter exactly once and reuses it across
+//! every `textDocument/*` notification for the rest of the process.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Read, Write};
+
+use pyoxidizer::pyembed::{PyObject, Python};
+use serde_json::{json, Value};
+
+use crate::py_json;
+
+/// Run the LSP server on stdio until the client sends `exit` or stdin
+/// closes. `py` is the single, long-lived interpreter handle shared by
+/// every request.
+pub fn serve(py: Python) -> io::Result<()> {
+    let stdin = io::stdin();
+    let mut reader = stdin.lock();
+    let stdout = io::stdout();
+
+    // The lint callable is resolved once and reused for every buffer we
+    // see, avoiding a fresh `import` per keystroke.
+    let clipfix_lint = py.import("clipfix.lint")?.getattr("lint_source")?;
+
+    // Per-URI last-known-good text, kept so a bare `didSave` (the client
+    // is never required to send the full text on save) can still be
+    // relinted against the buffer we already have instead of "".
+    let mut documents: HashMap<String, String> = HashMap::new();
+
+    loop {
+        let message = match read_message(&mut reader)? {
+            Some(message) => message,
+            None => break,
+        };
+
+        let method = message["method"].as_str().unwrap_or("").to_string();
+        match method.as_str() {
+            "initialize" => {
+                let response = json!({
+                    "jsonrpc": "2.0",
+                    "id": message["id"],
+                    "result": {
+                        "capabilities": {
+                            "textDocumentSync": {
+                                "openClose": true,
+                                "change": 1,
+                                // Ask the client to include the full text on
+                                // save; `documents` covers clients that don't.
+                                "save": {"includeText": true}
+                            },
+                            "codeActionProvider": true
+                        }
+                    }
+                });
+                write_message(&mut stdout.lock(), &response)?;
+            }
+            "textDocument/didOpen" | "textDocument/didChange" | "textDocument/didSave" => {
+                let (uri, text) = sync_document(&method, &message, &mut documents);
+                publish_diagnostics(py, &clipfix_lint, &uri, &text, &mut stdout.lock())?;
+            }
+            "textDocument/codeAction" => {
+                let uri = message["params"]["textDocument"]["uri"]
+                    .as_str()
+                    .unwrap_or("")
+                    .to_string();
+                let text = documents.get(&uri).cloned().unwrap_or_default();
+                let response = code_action_response(py, &clipfix_lint, &uri, &text, &message)?;
+                write_message(&mut stdout.lock(), &response)?;
+            }
+            "exit" => break,
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Update the per-URI text cache from a `didOpen`/`didChange`/`didSave`
+/// notification and return the URI together with the text to lint.
+///
+/// `didOpen` carries the full text on `textDocument.text`; `didChange`
+/// carries it on the first content change; `didSave`'s optional full
+/// text (`TextDocumentSaveParams.text`) is a top-level `params.text`. If
+/// a `didSave` doesn't carry it, fall back to the last text we cached
+/// from that document's `didOpen`/`didChange` rather than relinting "".
+fn sync_document(method: &str, message: &Value, documents: &mut HashMap<String, String>) -> (String, String) {
+    let params = &message["params"];
+    let uri = params["textDocument"]["uri"].as_str().unwrap_or("").to_string();
+
+    let new_text = match method {
+        "textDocument/didOpen" => params["textDocument"]["text"].as_str(),
+        "textDocument/didChange" => params["contentChanges"][0]["text"].as_str(),
+        "textDocument/didSave" => params["text"].as_str(),
+        _ => None,
+    };
+
+    let text = match new_text {
+        Some(text) => {
+            documents.insert(uri.clone(), text.to_string());
+            text.to_string()
+        }
+        None => documents.get(&uri).cloned().unwrap_or_default(),
+    };
+
+    (uri, text)
+}
+
+/// Call `clipfix.lint.lint_source` and convert its return value into a
+/// JSON array of findings, each carrying `rule_id`, `level`, `message`,
+/// a 1-based `start_line`/`start_column`/`end_line`/`end_column`, and an
+/// optional `fix.replacement` for autofixable lints.
+fn lint(py: Python, clipfix_lint: &PyObject, text: &str) -> io::Result<Vec<Value>> {
+    let result = clipfix_lint.call1(py, (text,))?;
+    let findings = py_json::to_value(py, result.as_ref(py))?;
+    Ok(findings.as_array().cloned().unwrap_or_default())
+}
+
+fn publish_diagnostics(
+    py: Python,
+    clipfix_lint: &PyObject,
+    uri: &str,
+    text: &str,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let findings = lint(py, clipfix_lint, text)?;
+    let diagnostics: Vec<Value> = findings.iter().map(finding_to_diagnostic).collect();
+
+    let notification = json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": {
+            "uri": uri,
+            "diagnostics": diagnostics
+        }
+    });
+    write_message(out, &notification)
+}
+
+fn code_action_response(
+    py: Python,
+    clipfix_lint: &PyObject,
+    uri: &str,
+    text: &str,
+    message: &Value,
+) -> io::Result<Value> {
+    let findings = lint(py, clipfix_lint, text)?;
+    let actions: Vec<Value> = findings
+        .iter()
+        .filter_map(|finding| finding_to_code_action(uri, finding))
+        .collect();
+
+    Ok(json!({
+        "jsonrpc": "2.0",
+        "id": message["id"],
+        "result": actions
+    }))
+}
+
+/// LSP ranges are 0-based; clipfix reports 1-based lines and columns.
+fn range(finding: &Value) -> Value {
+    let zero_based = |key: &str| finding[key].as_i64().unwrap_or(1).saturating_sub(1);
+    json!({
+        "start": {"line": zero_based("start_line"), "character": zero_based("start_column")},
+        "end": {"line": zero_based("end_line"), "character": zero_based("end_column")}
+    })
+}
+
+fn severity(level: &str) -> i64 {
+    match level {
+        "error" => 1,
+        "note" | "info" => 3,
+        _ => 2,
+    }
+}
+
+fn finding_to_diagnostic(finding: &Value) -> Value {
+    json!({
+        "range": range(finding),
+        "severity": severity(finding["level"].as_str().unwrap_or("warning")),
+        "code": finding["rule_id"],
+        "source": "clipfix",
+        "message": finding["message"]
+    })
+}
+
+/// Turn a finding that carries a `fix.replacement` into an editable
+/// quick-fix `CodeAction`; findings with no autofix are skipped.
+fn finding_to_code_action(uri: &str, finding: &Value) -> Option<Value> {
+    let replacement = finding["fix"]["replacement"].as_str()?;
+    let rule_id = finding["rule_id"].as_str().unwrap_or("");
+
+    Some(json!({
+        "title": format!("Apply clipfix fix for `{rule_id}`"),
+        "kind": "quickfix",
+        "diagnostics": [finding_to_diagnostic(finding)],
+        "edit": {
+            "changes": {
+                uri: [
+                    {
+                        "range": range(finding),
+                        "newText": replacement
+                    }
+                ]
+            }
+        }
+    }))
+}
+
+fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some(value) = header.strip_prefix("Content-Length: ") {
+            content_length = value.parse::<usize>().ok();
+        }
+    }
+
+    let content_length = match content_length {
+        Some(length) => length,
+        None => return Ok(None),
+    };
+
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+    let value = serde_json::from_slice(&body)?;
+    Ok(Some(value))
+}
+
+fn write_message(out: &mut impl Write, value: &Value) -> io::Result<()> {
+    let body = serde_json::to_vec(value)?;
+    write!(out, "Content-Length: {}\r\n\r\n", body.len())?;
+    out.write_all(&body)?;
+    out.flush()
+}
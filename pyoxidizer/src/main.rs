@@ -1,15 +1,10 @@
-use pyoxidizer::pyembed;
+mod catalog;
+mod fix;
+mod launcher;
+mod lsp;
+mod py_json;
+mod sarif;
 
 fn main() {
-    pyembed::MainPythonInterpreter::new().run(|py| {
-        py.run(
-            r#"
-import sys
-from clipfix.main import main
-sys.exit(main())
-"#,
-            None,
-            None,
-        )
-    });
+    std::process::exit(launcher::run());
 }
@@ -0,0 +1,14 @@
+//! Conversion from embedded Python objects to `serde_json::Value`.
+//!
+//! pyo3 has no built-in `FromPyObject` for `serde_json::Value`, so every
+//! place that hands a Python return value to JSON-shaped Rust code (the
+//! LSP server, the SARIF renderer) goes through this one conversion.
+
+use pyoxidizer::pyembed::{Python, PyAny, PyResult};
+use pythonize::depythonize;
+
+/// Convert a Python object (dict/list/primitive, as returned by clipfix)
+/// into a `serde_json::Value`.
+pub fn to_value(_py: Python, obj: &PyAny) -> PyResult<serde_json::Value> {
+    depythonize(obj).map_err(Into::into)
+}
@@ -0,0 +1,88 @@
+//! SARIF 2.1.0 rendering for `--format sarif`.
+//!
+//! `clipfix` still does its own linting in Python and reports structured
+//! findings back over the embedded interpreter boundary; this module
+//! reshapes those findings, together with the shared [`crate::catalog`]
+//! rule metadata, into the SARIF document CI dashboards (GitHub code
+//! scanning among them) expect. The text format clipfix prints itself
+//! remains the default — SARIF is opt-in.
+
+use serde_json::{json, Value};
+
+use crate::catalog::Rule;
+
+const SCHEMA_URI: &str = "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json";
+const TOOL_NAME: &str = "clipfix";
+
+/// Build a full SARIF log from a tool version, the rule catalog, and the
+/// findings from a lint run.
+///
+/// `findings` are passed through as the JSON values clipfix returns over
+/// the embedded interpreter boundary; `rules` comes from the shared Rust
+/// catalog so the `rules` array stays consistent with `explain` and
+/// `list-lints`.
+pub fn render(tool_version: &str, rules: &[Rule], findings: &[Value]) -> Value {
+    json!({
+        "$schema": SCHEMA_URI,
+        "version": "2.1.0",
+        "runs": [
+            {
+                "tool": {
+                    "driver": {
+                        "name": TOOL_NAME,
+                        "version": tool_version,
+                        "rules": rules.iter().map(rule_to_sarif).collect::<Vec<_>>()
+                    }
+                },
+                "results": findings.iter().map(finding_to_sarif).collect::<Vec<_>>()
+            }
+        ]
+    })
+}
+
+fn rule_to_sarif(rule: &Rule) -> Value {
+    json!({
+        "id": rule.id,
+        "shortDescription": {
+            "text": rule.description
+        },
+        "defaultConfiguration": {
+            "level": sarif_level(rule.level.name())
+        }
+    })
+}
+
+fn finding_to_sarif(finding: &Value) -> Value {
+    json!({
+        "ruleId": finding["rule_id"],
+        "level": sarif_level(finding["level"].as_str().unwrap_or("warning")),
+        "message": {
+            "text": finding["message"]
+        },
+        "locations": [
+            {
+                "physicalLocation": {
+                    "artifactLocation": {
+                        "uri": finding["path"]
+                    },
+                    "region": {
+                        "startLine": finding["start_line"],
+                        "startColumn": finding["start_column"],
+                        "endLine": finding["end_line"],
+                        "endColumn": finding["end_column"]
+                    }
+                }
+            }
+        ]
+    })
+}
+
+/// Map clipfix's own severity vocabulary onto SARIF's `error` / `warning`
+/// / `note` levels.
+fn sarif_level(level: &str) -> &'static str {
+    match level {
+        "error" => "error",
+        "note" | "info" => "note",
+        _ => "warning",
+    }
+}